@@ -1,4 +1,5 @@
 import math
+import os
 from pathlib import Path
 import threading
 import time
@@ -24,7 +25,9 @@ from utils import print_event
 class Trainer:
     TRAINING_LOCK = threading.Lock()
     SAVED_MODELS: Dict[str, Path] = {}
-    BASE_URL = "http://localhost:8000/api/v0.1/pods"
+    BASE_URL = (
+        os.environ.get("SPICE_RUNTIME_URL", "http://localhost:8000") + "/api/v0.1/pods"
+    )
 
     def __init__(
         self,
@@ -14,6 +14,7 @@ import (
 	"time"
 
 	"github.com/logrusorgru/aurora"
+	"github.com/spiceai/spiceai/pkg/constants"
 	spice_context "github.com/spiceai/spiceai/pkg/context"
 	"github.com/spiceai/spiceai/pkg/loggers"
 	"github.com/spiceai/spiceai/pkg/proto/aiengine_pb"
@@ -38,10 +39,17 @@ var (
 	aiServerRunning     chan bool
 	aiServerReady       bool = false
 	aiSingleTrainingRun bool = false
+	runtimeUrl          string
 	shutdownMutex       sync.Mutex
 	zaplog              *zap.Logger = loggers.ZapLogger()
 )
 
+// SetRuntimeUrl sets the runtime HTTP address the AI engine reports training
+// episodes back to. Must be called before StartServer.
+func SetRuntimeUrl(url string) {
+	runtimeUrl = url
+}
+
 func StartServer(ready chan bool, isSingleRun bool) error {
 	if aiServerRunning != nil {
 		return errors.New("ai engine already started")
@@ -61,6 +69,13 @@ func StartServer(ready chan bool, isSingleRun bool) error {
 	aiServerPath := filepath.Join(rtcontext.AIEngineDir(), pythonServerFilename)
 	shutdownMutex.Lock()
 	aiServerCmd = execCommand(rtcontext.AIEnginePythonCmdPath(), aiServerPath)
+	if runtimeUrl != "" {
+		env := aiServerCmd.Env
+		if env == nil {
+			env = os.Environ()
+		}
+		aiServerCmd.Env = append(env, fmt.Sprintf("%s=%s", constants.SpiceRuntimeUrlEnvVar, runtimeUrl))
+	}
 	shutdownMutex.Unlock()
 	aiServerRunning := make(chan bool, 1)
 
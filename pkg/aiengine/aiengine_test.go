@@ -48,6 +48,7 @@ func TestAIEngineStartServer(t *testing.T) {
 
 	t.Run("StartServer() -- Happy Path", testStartServerFunc())
 	t.Run("StartServer() -- Python server takes a few tries to return healthy", testStartServerHealthyLaterFunc())
+	t.Run("StartServer() -- Runtime url is passed to the Python server", testStartServerRuntimeUrlFunc())
 }
 
 func TestInfer(t *testing.T) {
@@ -319,6 +320,43 @@ func testStartServerFunc() func(*testing.T) {
 	}
 }
 
+func testStartServerRuntimeUrlFunc() func(*testing.T) {
+	return func(t *testing.T) {
+		execCommand = testutils.GetScenarioExecCommand("HAPPY_PATH")
+		t.Cleanup(func() {
+			getClient = NewAIEngineClient
+			aiengineClient = nil
+			aiServerCmd = nil
+			runtimeUrl = ""
+		})
+
+		aiengineClient = nil
+		aiServerCmd = nil
+
+		mockAIEngineClient := &MockAIEngineClient{
+			GetHealthHandler: func(c go_context.Context, healthRequest *aiengine_pb.HealthRequest, co ...grpc.CallOption) (*aiengine_pb.Response, error) {
+				return &aiengine_pb.Response{
+					Result: "ok",
+				}, nil
+			},
+		}
+
+		getClient = func(target string) (AIEngineClient, error) {
+			return mockAIEngineClient, nil
+		}
+
+		SetRuntimeUrl("http://127.0.0.1:43210")
+
+		ready := make(chan bool)
+		err := StartServer(ready, false)
+		assert.NoError(t, err)
+		<-ready
+		assert.NotNil(t, aiServerCmd)
+		assert.Contains(t, aiServerCmd.Env, "GO_WANT_HELPER_PROCESS=1")
+		assert.Contains(t, aiServerCmd.Env, "SPICE_RUNTIME_URL=http://127.0.0.1:43210")
+	}
+}
+
 func testStartServerHealthyLaterFunc() func(*testing.T) {
 	return func(t *testing.T) {
 		execCommand = testutils.GetScenarioExecCommand("HAPPY_PATH")
@@ -0,0 +1,28 @@
+package api
+
+type ComponentHealth struct {
+	Type   string `json:"type"`
+	Name   string `json:"name"`
+	Ready  bool   `json:"ready"`
+	Reason string `json:"reason,omitempty"`
+}
+
+type Readiness struct {
+	Ready      bool               `json:"ready"`
+	Components []*ComponentHealth `json:"components"`
+}
+
+func NewReadiness(components ...*ComponentHealth) *Readiness {
+	ready := true
+	for _, c := range components {
+		if !c.Ready {
+			ready = false
+			break
+		}
+	}
+
+	return &Readiness{
+		Ready:      ready,
+		Components: components,
+	}
+}
@@ -17,8 +17,10 @@ func TestCLI(t *testing.T) {
 
 	// These tests simulate a real workflow and should run in sequence
 	t.Run("initCmd() - spice init foo creates a skeleton pod", testInit(cmd.RootCmd))
+	t.Run("podsValidateCmd() - spice pods validate reports every skeleton problem", testPodsValidateSkeletonCmd(cmd.RootCmd))
 	t.Run("actionAddCmd() - spice action add jump adds an action", testActionAddCmd(cmd.RootCmd))
 	t.Run("rewardAddCmd() - spice reward add adds default rewards", testRewardsAddCmd(cmd.RootCmd))
+	t.Run("podsValidateCmd() - spice pods validate no longer reports the action once its reward exists", testPodsValidateWithActionCmd(cmd.RootCmd))
 }
 
 func init() {
@@ -99,3 +101,27 @@ func testRewardsAddCmd(root *cobra.Command) func(*testing.T) {
 		assert.Contains(t, pod.Rewards(), "jump")
 	}
 }
+
+// Tests pods validate reports all of the skeleton pod's problems in one run
+func testPodsValidateSkeletonCmd(root *cobra.Command) func(*testing.T) {
+	return func(t *testing.T) {
+		_, err := executeCommand(root, "pods", "validate", "spicepods/foo.yaml")
+		assert.Error(t, err)
+
+		assert.Contains(t, err.Error(), "pod 'foo' is not valid for training")
+		assert.Contains(t, err.Error(), "invalid dataspace \"from\"")
+		assert.Contains(t, err.Error(), "invalid dataspace \"name\"")
+	}
+}
+
+// Tests pods validate no longer reports the action once its reward exists, while the dataspace stays invalid
+func testPodsValidateWithActionCmd(root *cobra.Command) func(*testing.T) {
+	return func(t *testing.T) {
+		_, err := executeCommand(root, "pods", "validate", "spicepods/foo.yaml")
+		assert.Error(t, err)
+
+		assert.Contains(t, err.Error(), "invalid dataspace \"from\"")
+		assert.Contains(t, err.Error(), "invalid dataspace \"name\"")
+		assert.NotContains(t, err.Error(), "'jump'")
+	}
+}
@@ -4,15 +4,16 @@ import (
 	"encoding/json"
 	"fmt"
 	"io"
-	"net/http"
 	"sort"
 	"strings"
 
+	"github.com/logrusorgru/aurora"
 	"github.com/spf13/cobra"
 	"github.com/spf13/viper"
 	"github.com/spiceai/spiceai/pkg/api"
 	"github.com/spiceai/spiceai/pkg/config"
 	"github.com/spiceai/spiceai/pkg/context"
+	"github.com/spiceai/spiceai/pkg/pods"
 	"github.com/spiceai/spiceai/pkg/util"
 )
 
@@ -22,6 +23,7 @@ var podsCmd = &cobra.Command{
 	Short:   "Retrieve pods",
 	Example: `
 spice pods list
+spice pods validate spicepods/trader.yaml
 `,
 }
 
@@ -42,7 +44,13 @@ spice pods list
 
 		serverBaseUrl := runtimeConfig.ServerBaseUrl()
 
-		err = util.IsRuntimeServerHealthy(serverBaseUrl, http.DefaultClient)
+		httpClient, err := runtimeConfig.HttpClient()
+		if err != nil {
+			cmd.Println(err.Error())
+			return
+		}
+
+		err = util.IsRuntimeServerHealthy(serverBaseUrl, httpClient)
 		if err != nil {
 			cmd.Printf("failed to reach %s. is the spice runtime running?\n", serverBaseUrl)
 			return
@@ -50,7 +58,7 @@ spice pods list
 
 		listUrl := fmt.Sprintf("%s/api/v0.1/pods", serverBaseUrl)
 
-		response, err := http.DefaultClient.Get(listUrl)
+		response, err := httpClient.Get(listUrl)
 		if err != nil {
 			cmd.Printf("failed to get currently loaded pods from runtime: %s\n", err.Error())
 			return
@@ -89,9 +97,42 @@ spice pods list
 	},
 }
 
+var podsValidateCmd = &cobra.Command{
+	Use:   "validate",
+	Short: "Validates a pod manifest without starting the runtime",
+	Example: `
+spice pods validate spicepods/trader.yaml
+`,
+	Args:          cobra.ExactArgs(1),
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		manifestPath := args[0]
+
+		pod, err := pods.ReadPodManifest(manifestPath)
+		if err != nil {
+			return err
+		}
+
+		errs := pod.Validate()
+		if len(errs) > 0 {
+			problems := strings.Builder{}
+			for _, err := range errs {
+				problems.WriteString(fmt.Sprintf("\n- %s", strings.TrimSpace(err.Error())))
+			}
+			return fmt.Errorf("pod '%s' is not valid for training:%s", pod.Name, problems.String())
+		}
+
+		cmd.Println(aurora.Green(fmt.Sprintf("pod '%s' is valid", pod.Name)))
+		return nil
+	},
+}
+
 func init() {
 	podsCmd.AddCommand(podsListCmd)
+	podsCmd.AddCommand(podsValidateCmd)
 	podsCmd.Flags().BoolP("help", "h", false, "Prints this help message")
 	podsListCmd.Flags().BoolP("help", "h", false, "Prints this help message")
+	podsValidateCmd.Flags().BoolP("help", "h", false, "Prints this help message")
 	RootCmd.AddCommand(podsCmd)
 }
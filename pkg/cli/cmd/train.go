@@ -5,7 +5,6 @@ import (
 	"encoding/json"
 	"fmt"
 	"io"
-	"net/http"
 	"os"
 
 	"github.com/logrusorgru/aurora"
@@ -79,7 +78,13 @@ spice train LogPruner --loggers=tensorboard
 
 		serverBaseUrl := runtimeConfig.ServerBaseUrl()
 
-		err = util.IsRuntimeServerHealthy(serverBaseUrl, http.DefaultClient)
+		httpClient, err := runtimeConfig.HttpClient()
+		if err != nil {
+			cmd.Println(err.Error())
+			return
+		}
+
+		err = util.IsRuntimeServerHealthy(serverBaseUrl, httpClient)
 		if err != nil {
 			cmd.Printf("failed to reach %s. is the spice runtime running?\n", serverBaseUrl)
 			return
@@ -97,7 +102,7 @@ spice train LogPruner --loggers=tensorboard
 			return
 		}
 
-		response, err := http.DefaultClient.Post(trainUrl, "application/json", bytes.NewReader(trainRequestBytes))
+		response, err := httpClient.Post(trainUrl, "application/json", bytes.NewReader(trainRequestBytes))
 		if err != nil {
 			cmd.Printf("failed to start training: %s\n", err.Error())
 			return
@@ -17,6 +17,7 @@ import (
 type RuntimeClient struct {
 	runtimeConfig *config.SpiceConfiguration
 	serverBaseUrl string
+	httpClient    *http.Client
 }
 
 func NewRuntimeClient(podName string) (*RuntimeClient, error) {
@@ -29,14 +30,20 @@ func NewRuntimeClient(podName string) (*RuntimeClient, error) {
 
 	serverBaseUrl := runtimeConfig.ServerBaseUrl()
 
+	httpClient, err := runtimeConfig.HttpClient()
+	if err != nil {
+		return nil, err
+	}
+
 	return &RuntimeClient{
 		runtimeConfig: runtimeConfig,
 		serverBaseUrl: serverBaseUrl,
+		httpClient:    httpClient,
 	}, nil
 }
 
 func (r *RuntimeClient) ExportModel(podName string, directory string, filename string, tag string) error {
-	err := util.IsRuntimeServerHealthy(r.serverBaseUrl, http.DefaultClient)
+	err := util.IsRuntimeServerHealthy(r.serverBaseUrl, r.httpClient)
 	if err != nil {
 		return fmt.Errorf("failed to reach %s. is the spice runtime running? %w", r.serverBaseUrl, err)
 	}
@@ -52,7 +59,7 @@ func (r *RuntimeClient) ExportModel(podName string, directory string, filename s
 	}
 
 	exportModelUrl := fmt.Sprintf("%s/api/v0.1/pods/%s/models/%s/export", r.serverBaseUrl, podName, tag)
-	response, err := http.DefaultClient.Post(exportModelUrl, "application/json", bytes.NewReader(exportRequestBytes))
+	response, err := r.httpClient.Post(exportModelUrl, "application/json", bytes.NewReader(exportRequestBytes))
 	if err != nil {
 		return nil
 	}
@@ -69,7 +76,7 @@ func (r *RuntimeClient) ExportModel(podName string, directory string, filename s
 }
 
 func (r *RuntimeClient) ImportModel(podName string, archivePath string, tag string) error {
-	err := util.IsRuntimeServerHealthy(r.serverBaseUrl, http.DefaultClient)
+	err := util.IsRuntimeServerHealthy(r.serverBaseUrl, r.httpClient)
 	if err != nil {
 		return fmt.Errorf("failed to reach %s. is the spice runtime running? %w", r.serverBaseUrl, err)
 	}
@@ -86,7 +93,7 @@ func (r *RuntimeClient) ImportModel(podName string, archivePath string, tag stri
 	}
 
 	importModelUrl := fmt.Sprintf("%s/api/v0.1/pods/%s/models/%s/import", r.serverBaseUrl, podName, tag)
-	response, err := http.DefaultClient.Post(importModelUrl, "application/json", bytes.NewReader(importRequestBytes))
+	response, err := r.httpClient.Post(importModelUrl, "application/json", bytes.NewReader(importRequestBytes))
 	if err != nil {
 		return nil
 	}
@@ -103,13 +110,13 @@ func (r *RuntimeClient) ImportModel(podName string, archivePath string, tag stri
 }
 
 func (r *RuntimeClient) StartTraining(podName string) error {
-	err := util.IsRuntimeServerHealthy(r.serverBaseUrl, http.DefaultClient)
+	err := util.IsRuntimeServerHealthy(r.serverBaseUrl, r.httpClient)
 	if err != nil {
 		return fmt.Errorf("failed to reach %s. is the spice runtime running? %w", r.serverBaseUrl, err)
 	}
 
 	trainUrl := fmt.Sprintf("%s/api/v0.1/pods/%s/train", r.serverBaseUrl, podName)
-	response, err := http.DefaultClient.Post(trainUrl, "application/json", nil)
+	response, err := r.httpClient.Post(trainUrl, "application/json", nil)
 	if err != nil {
 		return fmt.Errorf("failed to start training: %w", err)
 	}
@@ -4,6 +4,7 @@ import (
 	"fmt"
 	"io"
 	"log"
+	"net/http"
 	"os"
 	"reflect"
 	"strconv"
@@ -19,10 +20,19 @@ import (
 func TestConfig(t *testing.T) {
 	testConfigPath := "../../test/assets/config/config.yaml"
 	testConfigPathWithEnvVars := "../../test/assets/config/config_with_env_vars.yaml"
+	testConfigPathWithTls := "../../test/assets/config/config_with_tls.yaml"
+	testConfigPathWithPartialTls := "../../test/assets/config/config_with_partial_tls.yaml"
+	testConfigPathWithProfilingWithoutToken := "../../test/assets/config/config_with_profiling_without_token.yaml"
 	t.Cleanup(testutils.CleanupTestSpiceDirectory)
 	t.Run("LoadRuntimeConfiguration() - Config loads correctly", testRuntimeConfigLoads(testConfigPath))
 	testutils.CleanupTestSpiceDirectory()
 	t.Run("LoadRuntimeConfiguration() - Environment variables in config are replaced", testRuntimeConfigReplacesEnvironmentVariables(testConfigPathWithEnvVars))
+	testutils.CleanupTestSpiceDirectory()
+	t.Run("LoadRuntimeConfiguration() - TLS settings load correctly", testRuntimeConfigLoadsTls(testConfigPathWithTls))
+	testutils.CleanupTestSpiceDirectory()
+	t.Run("LoadRuntimeConfiguration() - TLS requires both certificate and key", testRuntimeConfigRejectsPartialTls(testConfigPathWithPartialTls))
+	testutils.CleanupTestSpiceDirectory()
+	t.Run("LoadRuntimeConfiguration() - Profiling requires a token", testRuntimeConfigRejectsProfilingWithoutToken(testConfigPathWithProfilingWithoutToken))
 }
 
 // Tests configuration loads correctly
@@ -82,6 +92,67 @@ func testRuntimeConfigReplacesEnvironmentVariables(testConfigPath string) func(*
 	}
 }
 
+// Tests TLS settings load and switch the server url to https
+func testRuntimeConfigLoadsTls(testConfigPath string) func(*testing.T) {
+	return func(t *testing.T) {
+		testutils.EnsureTestSpiceDirectory(t)
+
+		tempConfigPath := "spice.config.yaml"
+		copyFile(testConfigPath, tempConfigPath)
+		defer os.Remove(tempConfigPath)
+
+		viper := viper.New()
+		rtcontext := context.CurrentContext()
+		spiceConfiguration, err := config.LoadRuntimeConfiguration(viper, rtcontext.AppDir())
+		if err != nil {
+			t.Error(err)
+			return
+		}
+
+		assert.Equal(t, "/etc/spice/tls/tls.crt", spiceConfiguration.TlsCertFile)
+		assert.Equal(t, "/etc/spice/tls/tls.key", spiceConfiguration.TlsKeyFile)
+		assert.Equal(t, "spice.example.com", spiceConfiguration.TlsServerName)
+		assert.True(t, spiceConfiguration.TlsEnabled())
+		assert.Equal(t, "https://localhost:8443", spiceConfiguration.ServerBaseUrl())
+
+		httpClient, err := spiceConfiguration.HttpClient()
+		assert.NoError(t, err)
+		assert.NotEqual(t, http.DefaultClient, httpClient)
+	}
+}
+
+// Tests a certificate without a key is rejected rather than silently serving plain HTTP
+func testRuntimeConfigRejectsPartialTls(testConfigPath string) func(*testing.T) {
+	return func(t *testing.T) {
+		testutils.EnsureTestSpiceDirectory(t)
+
+		tempConfigPath := "spice.config.yaml"
+		copyFile(testConfigPath, tempConfigPath)
+		defer os.Remove(tempConfigPath)
+
+		viper := viper.New()
+		rtcontext := context.CurrentContext()
+		_, err := config.LoadRuntimeConfiguration(viper, rtcontext.AppDir())
+		assert.EqualError(t, err, "both tls_cert_file and tls_key_file must be set to enable TLS")
+	}
+}
+
+// Tests profiling can't be enabled without a token, since loopback alone doesn't stop proxied callers
+func testRuntimeConfigRejectsProfilingWithoutToken(testConfigPath string) func(*testing.T) {
+	return func(t *testing.T) {
+		testutils.EnsureTestSpiceDirectory(t)
+
+		tempConfigPath := "spice.config.yaml"
+		copyFile(testConfigPath, tempConfigPath)
+		defer os.Remove(tempConfigPath)
+
+		viper := viper.New()
+		rtcontext := context.CurrentContext()
+		_, err := config.LoadRuntimeConfiguration(viper, rtcontext.AppDir())
+		assert.EqualError(t, err, "http_profiling requires http_profiling_token to be set")
+	}
+}
+
 func copyFile(fromPath string, toPath string) {
 	from, err := os.Open(fromPath)
 	if err != nil {
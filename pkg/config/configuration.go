@@ -2,7 +2,11 @@ package config
 
 import (
 	"bytes"
+	"crypto/tls"
+	"crypto/x509"
+	"errors"
 	"fmt"
+	"net/http"
 	"os"
 
 	"github.com/spf13/viper"
@@ -14,6 +18,24 @@ import (
 type SpiceConfiguration struct {
 	HttpPort        uint `json:"http_port,omitempty" mapstructure:"http_port,omitempty" yaml:"http_port,omitempty"`
 	DevelopmentMode bool `json:"development_mode,omitempty" mapstructure:"development_mode,omitempty" yaml:"development_mode,omitempty"`
+
+	// tls_cert_file and tls_key_file serve the runtime over TLS, reloading the
+	// key pair when either file changes
+	TlsCertFile string `json:"tls_cert_file,omitempty" mapstructure:"tls_cert_file,omitempty" yaml:"tls_cert_file,omitempty"`
+	TlsKeyFile  string `json:"tls_key_file,omitempty" mapstructure:"tls_key_file,omitempty" yaml:"tls_key_file,omitempty"`
+	// tls_server_name and tls_ca_file control how the CLI verifies the runtime's certificate
+	TlsServerName string `json:"tls_server_name,omitempty" mapstructure:"tls_server_name,omitempty" yaml:"tls_server_name,omitempty"`
+	TlsCaFile     string `json:"tls_ca_file,omitempty" mapstructure:"tls_ca_file,omitempty" yaml:"tls_ca_file,omitempty"`
+
+	// http_compression lists the route groups ("api", "dashboard") whose responses
+	// are compressed based on the client's Accept-Encoding
+	HttpCompression []string `json:"http_compression,omitempty" mapstructure:"http_compression,omitempty" yaml:"http_compression,omitempty"`
+	// http_profiling serves Go pprof profiles under /debug/pprof/ to loopback callers
+	// sending "Authorization: Bearer <http_profiling_token>". The loopback check does
+	// not stop remote callers forwarded by a proxy on the same host (a sidecar or
+	// nginx), so the token is required and must be kept secret.
+	HttpProfiling      bool   `json:"http_profiling,omitempty" mapstructure:"http_profiling,omitempty" yaml:"http_profiling,omitempty"`
+	HttpProfilingToken string `json:"http_profiling_token,omitempty" mapstructure:"http_profiling_token,omitempty" yaml:"http_profiling_token,omitempty"`
 }
 
 func LoadDefaultConfiguration() *SpiceConfiguration {
@@ -56,13 +78,67 @@ func LoadRuntimeConfiguration(v *viper.Viper, appDir string) (*SpiceConfiguratio
 		return nil, err
 	}
 
+	if (config.TlsCertFile == "") != (config.TlsKeyFile == "") {
+		return nil, errors.New("both tls_cert_file and tls_key_file must be set to enable TLS")
+	}
+
+	if config.HttpProfiling && config.HttpProfilingToken == "" {
+		return nil, errors.New("http_profiling requires http_profiling_token to be set")
+	}
+
+	for _, group := range config.HttpCompression {
+		if group != "api" && group != "dashboard" {
+			return nil, fmt.Errorf("invalid http_compression group '%s': choose from ['api', 'dashboard']", group)
+		}
+	}
+
 	return config, err
 }
 
+func (rtConfig *SpiceConfiguration) TlsEnabled() bool {
+	return rtConfig.TlsCertFile != "" && rtConfig.TlsKeyFile != ""
+}
+
 func (rtConfig *SpiceConfiguration) ServerBaseUrl() string {
+	if rtConfig.TlsEnabled() {
+		return fmt.Sprintf("https://localhost:%d", rtConfig.HttpPort)
+	}
 	return fmt.Sprintf("http://localhost:%d", rtConfig.HttpPort)
 }
 
+// HttpClient returns a client for calling the runtime at ServerBaseUrl(). With
+// TLS enabled, the certificate is verified against tls_server_name (the
+// certificate is usually issued for a real hostname, not localhost) using the
+// CA in tls_ca_file, falling back to the system roots.
+func (rtConfig *SpiceConfiguration) HttpClient() (*http.Client, error) {
+	if !rtConfig.TlsEnabled() {
+		return http.DefaultClient, nil
+	}
+
+	tlsConfig := &tls.Config{
+		MinVersion: tls.VersionTLS12,
+		ServerName: rtConfig.TlsServerName,
+	}
+
+	if rtConfig.TlsCaFile != "" {
+		caBytes, err := os.ReadFile(rtConfig.TlsCaFile)
+		if err != nil {
+			return nil, fmt.Errorf("error reading %s: %w", rtConfig.TlsCaFile, err)
+		}
+
+		rootCAs := x509.NewCertPool()
+		if !rootCAs.AppendCertsFromPEM(caBytes) {
+			return nil, fmt.Errorf("no certificates found in %s", rtConfig.TlsCaFile)
+		}
+		tlsConfig.RootCAs = rootCAs
+	}
+
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+	transport.TLSClientConfig = tlsConfig
+
+	return &http.Client{Transport: transport}, nil
+}
+
 func (rtConfig *SpiceConfiguration) WriteToFile() error {
 	configPath := fmt.Sprintf("%s.yaml", constants.SpiceConfigBaseName)
 	configFile, err := os.Create(configPath)
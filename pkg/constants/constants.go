@@ -9,4 +9,5 @@ const (
 	PythonCmd              = "python3"
 	SpiceEnvVarPrefix      = "SPICE_"
 	SpiceCliFilename       = "spice"
+	SpiceRuntimeUrlEnvVar  = "SPICE_RUNTIME_URL"
 )
@@ -2,6 +2,7 @@ package environment
 
 import (
 	"context"
+	"sync"
 
 	"github.com/spiceai/spiceai/pkg/aiengine"
 	"github.com/spiceai/spiceai/pkg/pods"
@@ -9,12 +10,29 @@ import (
 	"golang.org/x/sync/errgroup"
 )
 
-var firstInitCompleted bool = false
+var (
+	statusMutex        sync.RWMutex
+	firstInitCompleted bool             = false
+	podInitResults     map[string]error = make(map[string]error)
+)
 
 func FirstInitializationCompleted() bool {
+	statusMutex.RLock()
+	defer statusMutex.RUnlock()
+
 	return firstInitCompleted
 }
 
+// PodDataConnectorStatus reports whether the pod's data connectors have been
+// initialized, and the error if the last initialization failed
+func PodDataConnectorStatus(podName string) (bool, error) {
+	statusMutex.RLock()
+	defer statusMutex.RUnlock()
+
+	err, attempted := podInitResults[podName]
+	return attempted && err == nil, err
+}
+
 func InitDataConnectors() error {
 	errGroup, _ := errgroup.WithContext(context.Background())
 	for _, pod := range pods.Pods() {
@@ -25,7 +43,9 @@ func InitDataConnectors() error {
 	}
 	err := errGroup.Wait()
 	if err == nil {
+		statusMutex.Lock()
 		firstInitCompleted = true
+		statusMutex.Unlock()
 	}
 	return err
 }
@@ -35,9 +55,27 @@ func InitPodDataConnector(pod *pods.Pod) error {
 		return aiengine.SendData(pod, state)
 	}
 	err := pod.InitDataConnectors(handler)
+	recordPodInitResult(pod.Name, err)
 	if err != nil {
 		return err
 	}
 
 	return nil
 }
+
+// ForgetPod clears the pod's data connector status so it reports as
+// initializing until InitPodDataConnector records a fresh result. Call it when
+// a pod is removed or before it is re-created.
+func ForgetPod(podName string) {
+	statusMutex.Lock()
+	defer statusMutex.Unlock()
+
+	delete(podInitResults, podName)
+}
+
+func recordPodInitResult(podName string, err error) {
+	statusMutex.Lock()
+	defer statusMutex.Unlock()
+
+	podInitResults[podName] = err
+}
@@ -2,6 +2,7 @@ package environment
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"testing"
 	"time"
@@ -15,6 +16,60 @@ import (
 
 func TestEnvironment(t *testing.T) {
 	t.Run("RegisterStateHandlers() -- Should register handlers and post data", testRegisterStateHandlers())
+	t.Run("PodDataConnectorStatus() -- Reports initialization result per pod", testPodDataConnectorStatus())
+	t.Run("ForgetPod() -- Recreated pod reports initializing until reinitialized", testForgetPod())
+}
+
+func testForgetPod() func(*testing.T) {
+	return func(t *testing.T) {
+		t.Cleanup(func() {
+			delete(podInitResults, "forget_test_ok")
+			delete(podInitResults, "forget_test_failed")
+		})
+
+		recordPodInitResult("forget_test_ok", nil)
+		recordPodInitResult("forget_test_failed", errors.New("connection refused"))
+
+		// Pods removed, then recreated under the same names
+		ForgetPod("forget_test_ok")
+		ForgetPod("forget_test_failed")
+
+		for _, podName := range []string{"forget_test_ok", "forget_test_failed"} {
+			initialized, err := PodDataConnectorStatus(podName)
+			assert.False(t, initialized, podName)
+			assert.NoError(t, err, podName)
+		}
+		assert.NotContains(t, podInitResults, "forget_test_ok")
+		assert.NotContains(t, podInitResults, "forget_test_failed")
+
+		recordPodInitResult("forget_test_ok", nil)
+		initialized, err := PodDataConnectorStatus("forget_test_ok")
+		assert.True(t, initialized)
+		assert.NoError(t, err)
+	}
+}
+
+func testPodDataConnectorStatus() func(*testing.T) {
+	return func(t *testing.T) {
+		t.Cleanup(func() {
+			delete(podInitResults, "status_test_ok")
+			delete(podInitResults, "status_test_failed")
+		})
+
+		initialized, err := PodDataConnectorStatus("status_test_ok")
+		assert.False(t, initialized)
+		assert.NoError(t, err)
+
+		recordPodInitResult("status_test_ok", nil)
+		initialized, err = PodDataConnectorStatus("status_test_ok")
+		assert.True(t, initialized)
+		assert.NoError(t, err)
+
+		recordPodInitResult("status_test_failed", errors.New("connection refused"))
+		initialized, err = PodDataConnectorStatus("status_test_failed")
+		assert.False(t, initialized)
+		assert.EqualError(t, err, "connection refused")
+	}
 }
 
 func testRegisterStateHandlers() func(*testing.T) {
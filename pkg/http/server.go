@@ -1,9 +1,13 @@
 package http
 
 import (
+	"crypto/subtle"
+	"crypto/tls"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"log"
+	"net"
 	"net/http"
 	"strings"
 	"time"
@@ -23,15 +27,22 @@ import (
 	"github.com/spiceai/spiceai/pkg/state"
 	spice_time "github.com/spiceai/spiceai/pkg/time"
 	"github.com/valyala/fasthttp"
+	"github.com/valyala/fasthttp/pprofhandler"
 	"go.uber.org/zap"
 )
 
 type ServerConfig struct {
-	Port uint
+	Port           uint
+	TlsCertFile    string
+	TlsKeyFile     string
+	Compression    []string
+	Profiling      bool
+	ProfilingToken string
 }
 
 type server struct {
-	config ServerConfig
+	config      ServerConfig
+	callbackUrl string
 }
 
 var (
@@ -59,6 +70,57 @@ func healthHandler(ctx *fasthttp.RequestCtx) {
 	fmt.Fprintf(ctx, "ok")
 }
 
+func healthLiveHandler(ctx *fasthttp.RequestCtx) {
+	fmt.Fprintf(ctx, "ok")
+}
+
+func healthReadyHandler(ctx *fasthttp.RequestCtx) {
+	aiEngineHealth := &api.ComponentHealth{Type: "aiengine", Name: "aiengine", Ready: true}
+	err := aiengine.IsAIEngineHealthy()
+	if err != nil {
+		aiEngineHealth.Ready = false
+		aiEngineHealth.Reason = err.Error()
+	}
+
+	environmentHealth := &api.ComponentHealth{Type: "environment", Name: "environment", Ready: true}
+	if !environment.FirstInitializationCompleted() {
+		environmentHealth.Ready = false
+		environmentHealth.Reason = "data connectors initializing"
+	}
+
+	components := []*api.ComponentHealth{aiEngineHealth, environmentHealth}
+
+	for _, name := range pods.PodNames() {
+		podHealth := &api.ComponentHealth{Type: "pod", Name: name}
+		initialized, err := environment.PodDataConnectorStatus(name)
+		switch {
+		case err != nil:
+			podHealth.Reason = fmt.Sprintf("data connector failed: %s", err.Error())
+		case !initialized:
+			podHealth.Reason = "data connectors initializing"
+		default:
+			podHealth.Ready = true
+		}
+		components = append(components, podHealth)
+	}
+
+	readiness := api.NewReadiness(components...)
+
+	response, err := json.Marshal(readiness)
+	if err != nil {
+		ctx.Response.SetStatusCode(http.StatusInternalServerError)
+		ctx.Response.SetBodyString(err.Error())
+		return
+	}
+
+	if !readiness.Ready {
+		ctx.Response.SetStatusCode(http.StatusServiceUnavailable)
+	}
+
+	ctx.Response.Header.SetContentType("application/json")
+	ctx.Response.SetBody(response)
+}
+
 func apiGetObservationsHandler(ctx *fasthttp.RequestCtx) {
 	podParam := ctx.UserValue("pod").(string)
 	pod := pods.GetPod(podParam)
@@ -550,6 +612,8 @@ func apiPostImportHandler(ctx *fasthttp.RequestCtx) {
 	importRequest.Pod = podName
 	importRequest.Tag = tag.(string)
 
+	environment.ForgetPod(importRequest.Pod)
+
 	pod, err := pods.ImportPod(importRequest.Pod, importRequest.ArchivePath)
 	if err != nil {
 		ctx.Response.SetStatusCode(500)
@@ -598,21 +662,93 @@ func (server *server) apiGetDiagnosticsHandler(ctx *fasthttp.RequestCtx) {
 	ctx.SetBodyString(report)
 }
 
-func NewServer(port uint) *server {
+// Route groups that can opt in to response compression via ServerConfig.Compression
+const (
+	CompressionGroupApi       = "api"
+	CompressionGroupDashboard = "dashboard"
+)
+
+// compressionGroup maps a request path to its compression route group. pprof
+// profiles are already gzipped, so they never belong to a group.
+func compressionGroup(path string) string {
+	switch {
+	case strings.HasPrefix(path, "/debug/pprof/"):
+		return ""
+	case strings.HasPrefix(path, "/api/"), strings.HasPrefix(path, "/health"):
+		return CompressionGroupApi
+	default:
+		return CompressionGroupDashboard
+	}
+}
+
+// compressHandler negotiates brotli, gzip or deflate response compression from
+// the request's Accept-Encoding header for the route groups that opted in.
+// fasthttp doesn't support zstd, so it isn't offered.
+func (server *server) compressHandler(handler fasthttp.RequestHandler) fasthttp.RequestHandler {
+	if len(server.config.Compression) == 0 {
+		return handler
+	}
+
+	groups := make(map[string]bool, len(server.config.Compression))
+	for _, group := range server.config.Compression {
+		groups[group] = true
+	}
+
+	compressed := fasthttp.CompressHandlerBrotliLevel(handler, fasthttp.CompressBrotliDefaultCompression, fasthttp.CompressDefaultCompression)
+
+	return func(ctx *fasthttp.RequestCtx) {
+		if groups[compressionGroup(string(ctx.Path()))] {
+			compressed(ctx)
+			return
+		}
+
+		handler(ctx)
+	}
+}
+
+func NewServer(config ServerConfig) *server {
 	return &server{
-		config: ServerConfig{
-			Port: port,
-		},
+		config: config,
 	}
 }
 
-func (server *server) Start() error {
+// profilingAuth only serves callers on this machine that present the shared
+// profiling token as "Authorization: Bearer <token>". The loopback check alone
+// isn't enough: a proxy on the same host forwards remote callers from 127.0.0.1.
+func profilingAuth(token string, handler fasthttp.RequestHandler) fasthttp.RequestHandler {
+	expected := []byte("Bearer " + token)
+
+	return func(ctx *fasthttp.RequestCtx) {
+		if !ctx.RemoteIP().IsLoopback() {
+			ctx.Response.SetStatusCode(http.StatusForbidden)
+			return
+		}
+
+		authorization := ctx.Request.Header.Peek("Authorization")
+		if token == "" || subtle.ConstantTimeCompare(authorization, expected) != 1 {
+			ctx.Response.SetStatusCode(http.StatusUnauthorized)
+			return
+		}
+
+		handler(ctx)
+	}
+}
+
+func (server *server) router() *router.Router {
 	r := router.New()
 	r.GET("/health", healthHandler)
+	r.GET("/health/live", healthLiveHandler)
+	r.GET("/health/ready", healthReadyHandler)
+
+	if server.config.Profiling {
+		// Registered per method: the GET tree's dashboard catch-all would win over ANY
+		pprofHandler := profilingAuth(server.config.ProfilingToken, pprofhandler.PprofHandler)
+		r.GET("/debug/pprof/{profile:*}", pprofHandler)
+		r.POST("/debug/pprof/{profile:*}", pprofHandler)
+	}
 
 	// Static Dashboard
 	dashboardServer := dashboard.NewDashboardEmbedded()
-	var err error
 
 	api := r.Group("/api/v0.1")
 	{
@@ -662,18 +798,73 @@ func (server *server) Start() error {
 	})
 	r.GET("/", dashboardServer.IndexHandler)
 
+	return r
+}
+
+func (server *server) Start() error {
+	r := server.router()
+
 	serverLogger, err := zap.NewStdLogAt(zaplog, zap.DebugLevel)
 	if err != nil {
 		return fmt.Errorf("failed to initialize logger: %w", err)
 	}
 	fastServer := &fasthttp.Server{
-		Handler: r.Handler,
+		Handler: server.compressHandler(r.Handler),
 		Logger:  serverLogger,
 	}
 
+	addr := fmt.Sprintf(":%d", server.config.Port)
+
+	if server.config.TlsCertFile == "" && server.config.TlsKeyFile == "" {
+		server.callbackUrl = fmt.Sprintf("http://localhost:%d", server.config.Port)
+
+		go func() {
+			log.Fatal(fastServer.ListenAndServe(addr))
+		}()
+
+		return nil
+	}
+
+	if server.config.TlsCertFile == "" || server.config.TlsKeyFile == "" {
+		return errors.New("both tls_cert_file and tls_key_file must be set to enable TLS")
+	}
+
+	reloader, err := newCertificateReloader(server.config.TlsCertFile, server.config.TlsKeyFile)
+	if err != nil {
+		return err
+	}
+
+	listener, err := net.Listen("tcp", addr)
+	if err != nil {
+		return fmt.Errorf("failed to listen on %s: %w", addr, err)
+	}
+
+	tlsListener := tls.NewListener(listener, &tls.Config{
+		MinVersion:     tls.VersionTLS12,
+		GetCertificate: reloader.GetCertificate,
+	})
+
+	// The AI engine posts training episodes back over plain HTTP, so give it a
+	// loopback-only listener rather than teaching it to verify our certificate
+	callbackListener, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		return fmt.Errorf("failed to listen for ai engine callbacks: %w", err)
+	}
+	server.callbackUrl = fmt.Sprintf("http://%s", callbackListener.Addr().String())
+
 	go func() {
-		log.Fatal(fastServer.ListenAndServe(fmt.Sprintf(":%d", server.config.Port)))
+		log.Fatal(fastServer.Serve(tlsListener))
+	}()
+
+	go func() {
+		log.Fatal(fastServer.Serve(callbackListener))
 	}()
 
 	return nil
 }
+
+// CallbackUrl is the plain HTTP address the AI engine should use to reach this
+// server. Only valid after Start.
+func (server *server) CallbackUrl() string {
+	return server.callbackUrl
+}
@@ -2,6 +2,8 @@ package http
 
 import (
 	"encoding/json"
+	"net"
+	"strings"
 	"testing"
 
 	"github.com/spiceai/spiceai/pkg/api"
@@ -22,6 +24,18 @@ func TestServer(t *testing.T) {
 
 	t.Run("getInterpretations()", testGetInterpretationsHandlerFunc(pod))
 	t.Run("postInterpretations()", testPostInterpretationsHandlerFunc(pod))
+	t.Run("healthLiveHandler()", testHealthLiveHandlerFunc())
+	t.Run("healthReadyHandler() - Not ready before initialization", testHealthReadyHandlerFunc(pod))
+	t.Run("router() - Profiling endpoints served to loopback callers with the token", testProfilingRouteFunc(true, "127.0.0.1", "Bearer s3cret", fasthttp.StatusOK))
+	t.Run("router() - Profiling endpoints rejected for loopback callers without the token", testProfilingRouteFunc(true, "127.0.0.1", "", fasthttp.StatusUnauthorized))
+	t.Run("router() - Profiling endpoints rejected for loopback callers with a wrong token", testProfilingRouteFunc(true, "127.0.0.1", "Bearer guess", fasthttp.StatusUnauthorized))
+	t.Run("router() - Profiling endpoints rejected for remote callers", testProfilingRouteFunc(true, "10.0.0.7", "Bearer s3cret", fasthttp.StatusForbidden))
+	t.Run("router() - Profiling endpoints not registered when disabled", testProfilingRouteFunc(false, "127.0.0.1", "Bearer s3cret", fasthttp.StatusNotFound))
+	t.Run("compressHandler() - Compresses opted-in api group", testCompressHandlerFunc([]string{CompressionGroupApi}, "/api/v0.1/pods", "gzip"))
+	t.Run("compressHandler() - Skips groups that did not opt in", testCompressHandlerFunc([]string{CompressionGroupApi}, "/static/js/main.js", ""))
+	t.Run("compressHandler() - Compresses opted-in dashboard group", testCompressHandlerFunc([]string{CompressionGroupDashboard}, "/static/js/main.js", "gzip"))
+	t.Run("compressHandler() - Never compresses pprof output", testCompressHandlerFunc([]string{CompressionGroupApi, CompressionGroupDashboard}, "/debug/pprof/heap", ""))
+	t.Run("compressHandler() - Passes through when disabled", testCompressHandlerFunc(nil, "/api/v0.1/pods", ""))
 }
 
 func testGetInterpretationsHandlerFunc(pod *pods.Pod) func(t *testing.T) {
@@ -85,3 +99,101 @@ func testPostInterpretationsHandlerFunc(pod *pods.Pod) func(t *testing.T) {
 		assert.Equal(t, interpretation, &interpretations[0])
 	}
 }
+
+func testHealthLiveHandlerFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		ctx := &fasthttp.RequestCtx{
+			Request: fasthttp.Request{},
+		}
+
+		healthLiveHandler(ctx)
+
+		assert.Equal(t, fasthttp.StatusOK, ctx.Response.StatusCode())
+		assert.Equal(t, "ok", string(ctx.Response.Body()))
+	}
+}
+
+func testHealthReadyHandlerFunc(pod *pods.Pod) func(t *testing.T) {
+	return func(t *testing.T) {
+		pods.CreateOrUpdatePod(pod)
+		t.Cleanup(func() { pods.RemovePod(pod.Name) })
+
+		ctx := &fasthttp.RequestCtx{
+			Request: fasthttp.Request{},
+		}
+
+		healthReadyHandler(ctx)
+
+		assert.Equal(t, fasthttp.StatusServiceUnavailable, ctx.Response.StatusCode())
+
+		var readiness api.Readiness
+		err := json.Unmarshal(ctx.Response.Body(), &readiness)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		assert.False(t, readiness.Ready)
+		assert.Equal(t, 3, len(readiness.Components))
+		for _, component := range readiness.Components {
+			assert.False(t, component.Ready, component.Name)
+			assert.NotEmpty(t, component.Reason, component.Name)
+		}
+
+		podHealth := readiness.Components[2]
+		assert.Equal(t, "pod", podHealth.Type)
+		assert.Equal(t, pod.Name, podHealth.Name)
+		assert.Equal(t, "data connectors initializing", podHealth.Reason)
+	}
+}
+
+func testProfilingRouteFunc(profiling bool, remoteIp string, authorization string, expectedStatus int) func(t *testing.T) {
+	return func(t *testing.T) {
+		server := NewServer(ServerConfig{Profiling: profiling, ProfilingToken: "s3cret"})
+		r := server.router()
+
+		expectProfile := expectedStatus == fasthttp.StatusOK
+
+		for _, path := range []string{"/debug/pprof/", "/debug/pprof/heap?debug=1"} {
+			var request fasthttp.Request
+			request.SetRequestURI(path)
+			if authorization != "" {
+				request.Header.Set("Authorization", authorization)
+			}
+
+			var ctx fasthttp.RequestCtx
+			ctx.Init(&request, &net.TCPAddr{IP: net.ParseIP(remoteIp)}, nil)
+
+			r.Handler(&ctx)
+
+			body := string(ctx.Response.Body())
+			isProfile := strings.Contains(body, "Types of profiles available") || strings.Contains(body, "heap profile")
+			assert.Equal(t, expectProfile, isProfile, path)
+			// With profiling off the dashboard catch-all answers, so only the body is checked
+			if profiling {
+				assert.Equal(t, expectedStatus, ctx.Response.StatusCode(), path)
+			}
+		}
+	}
+}
+
+func testCompressHandlerFunc(compression []string, path string, expectedEncoding string) func(t *testing.T) {
+	return func(t *testing.T) {
+		server := NewServer(ServerConfig{Compression: compression})
+
+		handler := server.compressHandler(func(ctx *fasthttp.RequestCtx) {
+			// Bodies shorter than fasthttp's minimum compression length are never compressed
+			ctx.Response.Header.SetContentType("text/plain")
+			ctx.Response.SetBodyString(strings.Repeat("spice", 100))
+		})
+
+		ctx := &fasthttp.RequestCtx{
+			Request: fasthttp.Request{},
+		}
+		ctx.Request.SetRequestURI(path)
+		ctx.Request.Header.Set("Accept-Encoding", "gzip")
+
+		handler(ctx)
+
+		assert.Equal(t, expectedEncoding, string(ctx.Response.Header.Peek("Content-Encoding")))
+	}
+}
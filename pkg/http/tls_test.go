@@ -0,0 +1,203 @@
+package http
+
+import (
+	"bytes"
+	"crypto/ecdsa"
+	"crypto/elliptic"
+	"crypto/rand"
+	"crypto/x509"
+	"crypto/x509/pkix"
+	"encoding/json"
+	"encoding/pem"
+	"fmt"
+	"math/big"
+	"net"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/spiceai/spiceai/pkg/flights"
+	"github.com/spiceai/spiceai/pkg/pods"
+	"github.com/spiceai/spiceai/pkg/proto/runtime_pb"
+	"github.com/stretchr/testify/assert"
+)
+
+func TestCertificateReloader(t *testing.T) {
+	t.Run("GetCertificate() - Loads initial certificate", testGetCertificateLoadsInitialFunc())
+	t.Run("GetCertificate() - Reloads certificate when files change", testGetCertificateReloadsFunc())
+	t.Run("GetCertificate() - Keeps last certificate when files are invalid", testGetCertificateKeepsLastGoodFunc())
+}
+
+func TestTlsServer(t *testing.T) {
+	manifestPath := "../../test/assets/pods/manifests/trader.yaml"
+
+	pod, err := pods.LoadPodFromManifest(manifestPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	pods.CreateOrUpdatePod(pod)
+	t.Cleanup(func() { pods.RemovePod(pod.Name) })
+
+	t.Run("Start() - AI engine can post episodes over plain HTTP with TLS enabled", testTlsServerCallbackFunc(pod))
+}
+
+func testTlsServerCallbackFunc(pod *pods.Pod) func(t *testing.T) {
+	return func(t *testing.T) {
+		certFile, keyFile := writeTestCertificate(t, t.TempDir(), "localhost", time.Now())
+
+		server := NewServer(ServerConfig{
+			Port:        freePort(t),
+			TlsCertFile: certFile,
+			TlsKeyFile:  keyFile,
+		})
+
+		err := server.Start()
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		assert.True(t, strings.HasPrefix(server.CallbackUrl(), "http://127.0.0.1:"), server.CallbackUrl())
+
+		flight, err := flights.NewFlight("tls", 1, "dql", nil, "")
+		if err != nil {
+			t.Fatal(err)
+		}
+		pod.AddFlight(flight.Id(), flight)
+
+		episode, err := json.Marshal(&runtime_pb.Episode{Episode: 1, Score: 1})
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		episodesUrl := fmt.Sprintf("%s/api/v0.1/pods/%s/training_runs/%s/episodes", server.CallbackUrl(), pod.Name, flight.Id())
+		response, err := http.Post(episodesUrl, "application/json", bytes.NewReader(episode))
+		if err != nil {
+			t.Fatal(err)
+		}
+		defer response.Body.Close()
+
+		assert.Equal(t, http.StatusCreated, response.StatusCode)
+		assert.Equal(t, 1, len(flight.Episodes()))
+	}
+}
+
+func freePort(t *testing.T) uint {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer listener.Close()
+
+	return uint(listener.Addr().(*net.TCPAddr).Port)
+}
+
+func testGetCertificateLoadsInitialFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		certFile, keyFile := writeTestCertificate(t, t.TempDir(), "first", time.Now())
+
+		reloader, err := newCertificateReloader(certFile, keyFile)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		certificate, err := reloader.GetCertificate(nil)
+		assert.NoError(t, err)
+		assert.Equal(t, "first", leafCommonName(t, certificate.Certificate[0]))
+	}
+}
+
+func testGetCertificateReloadsFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		dir := t.TempDir()
+		certFile, keyFile := writeTestCertificate(t, dir, "first", time.Now().Add(-time.Minute))
+
+		reloader, err := newCertificateReloader(certFile, keyFile)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		writeTestCertificate(t, dir, "second", time.Now())
+
+		certificate, err := reloader.GetCertificate(nil)
+		assert.NoError(t, err)
+		assert.Equal(t, "second", leafCommonName(t, certificate.Certificate[0]))
+	}
+}
+
+func testGetCertificateKeepsLastGoodFunc() func(t *testing.T) {
+	return func(t *testing.T) {
+		certFile, keyFile := writeTestCertificate(t, t.TempDir(), "first", time.Now().Add(-time.Minute))
+
+		reloader, err := newCertificateReloader(certFile, keyFile)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		err = os.WriteFile(certFile, []byte("not a certificate"), 0600)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		certificate, err := reloader.GetCertificate(nil)
+		assert.NoError(t, err)
+		assert.Equal(t, "first", leafCommonName(t, certificate.Certificate[0]))
+	}
+}
+
+func writeTestCertificate(t *testing.T, dir string, commonName string, modTime time.Time) (string, string) {
+	key, err := ecdsa.GenerateKey(elliptic.P256(), rand.Reader)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	template := &x509.Certificate{
+		SerialNumber: big.NewInt(time.Now().UnixNano()),
+		Subject:      pkix.Name{CommonName: commonName},
+		NotBefore:    time.Now().Add(-time.Hour),
+		NotAfter:     time.Now().Add(time.Hour),
+	}
+
+	certDer, err := x509.CreateCertificate(rand.Reader, template, template, &key.PublicKey, key)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	keyDer, err := x509.MarshalECPrivateKey(key)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	certFile := filepath.Join(dir, "tls.crt")
+	keyFile := filepath.Join(dir, "tls.key")
+
+	err = os.WriteFile(certFile, pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: certDer}), 0600)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = os.WriteFile(keyFile, pem.EncodeToMemory(&pem.Block{Type: "EC PRIVATE KEY", Bytes: keyDer}), 0600)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	for _, path := range []string{certFile, keyFile} {
+		err = os.Chtimes(path, modTime, modTime)
+		if err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	return certFile, keyFile
+}
+
+func leafCommonName(t *testing.T, der []byte) string {
+	leaf, err := x509.ParseCertificate(der)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	return leaf.Subject.CommonName
+}
@@ -769,27 +769,40 @@ func (pod *Pod) GetLogDir() (string, error) {
 }
 
 func (pod *Pod) ValidateForTraining() error {
+	errs := pod.Validate()
+	if len(errs) > 0 {
+		return errs[0]
+	}
+
+	return nil
+}
+
+// Validate checks the pod can be trained and returns every problem found.
+// Action problems are reported in action name order.
+func (pod *Pod) Validate() []error {
 	// Consider using something like https://github.com/go-playground/validator in the future
+	var errs []error
+
 	if pod.Granularity() > pod.Interval() {
-		return errors.New("granularity must be less than or equal to interval")
+		errs = append(errs, errors.New("granularity must be less than or equal to interval"))
 	}
 
 	if pod.Interval() > pod.Period() {
-		return errors.New("interval must be less than or equal to period")
+		errs = append(errs, errors.New("interval must be less than or equal to period"))
 	}
 
 	if pod.PodSpec.Dataspaces == nil || len(pod.PodSpec.Dataspaces) < 1 {
-		return errors.New("at least one dataspace is required for training")
+		errs = append(errs, errors.New("at least one dataspace is required for training"))
 	}
 
 	for _, ds := range pod.PodSpec.Dataspaces {
 		valid := validator.ValidateDataspaceName(ds.From)
 		if !valid {
-			return fmt.Errorf("invalid dataspace \"from\": '%s' should only contain A-Za-z0-9_", ds.From)
+			errs = append(errs, fmt.Errorf("invalid dataspace \"from\": '%s' should only contain A-Za-z0-9_", ds.From))
 		}
 		valid = validator.ValidateDataspaceName(ds.Name)
 		if !valid {
-			return fmt.Errorf("invalid dataspace \"name\": '%s' should only contain A-Za-z0-9_", ds.Name)
+			errs = append(errs, fmt.Errorf("invalid dataspace \"name\": '%s' should only contain A-Za-z0-9_", ds.Name))
 		}
 
 		for _, f := range ds.Measurements {
@@ -798,7 +811,7 @@ func (pod *Pod) ValidateForTraining() error {
 			case "previous":
 			case "none":
 			default:
-				return fmt.Errorf("invalid measurement fill '%s': choose one of ['previous', 'none']", f.Fill)
+				errs = append(errs, fmt.Errorf("invalid measurement fill '%s': choose one of ['previous', 'none']", f.Fill))
 			}
 		}
 	}
@@ -806,13 +819,20 @@ func (pod *Pod) ValidateForTraining() error {
 	actions := pod.Actions()
 
 	if len(actions) == 0 {
-		return errors.New("at least one action is required for training")
+		errs = append(errs, errors.New("at least one action is required for training"))
 	}
 
 	// Check for args.<arg name>
 	rewards := pod.Rewards()
 
-	for actionName, action := range actions {
+	actionNames := make([]string, 0, len(actions))
+	for actionName := range actions {
+		actionNames = append(actionNames, actionName)
+	}
+	sort.Strings(actionNames)
+
+	for _, actionName := range actionNames {
+		action := actions[actionName]
 		numErrors := 0
 		matches := validator.GetArgsRegex().FindStringSubmatch(action)
 		errorLines := strings.Builder{}
@@ -831,11 +851,11 @@ func (pod *Pod) ValidateForTraining() error {
 		}
 
 		if numErrors > 0 {
-			return errors.New(errorLines.String())
+			errs = append(errs, errors.New(errorLines.String()))
 		}
 	}
 
-	return nil
+	return errs
 }
 
 func (pod *Pod) AddLocalState(newState ...*state.State) {
@@ -79,8 +79,10 @@ func testValidateForTraining(pod *Pod, validForTraining bool) func(*testing.T) {
 	return func(t *testing.T) {
 		if validForTraining {
 			assert.NoError(t, pod.ValidateForTraining())
+			assert.Empty(t, pod.Validate())
 		} else {
 			assert.Error(t, pod.ValidateForTraining())
+			assert.NotEmpty(t, pod.Validate())
 		}
 	}
 }
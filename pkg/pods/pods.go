@@ -5,6 +5,7 @@ import (
 	"log"
 	"os"
 	"path/filepath"
+	"sort"
 	"sync"
 
 	"github.com/logrusorgru/aurora"
@@ -29,6 +30,20 @@ func Pods() map[string]*Pod {
 	return pods
 }
 
+// PodNames returns the sorted names of the loaded pods, safe to call while pods are being loaded
+func PodNames() []string {
+	podsMutex.RLock()
+	defer podsMutex.RUnlock()
+
+	names := make([]string, 0, len(pods))
+	for name := range pods {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	return names
+}
+
 func GetPod(name string) *Pod {
 	podsMutex.RLock()
 	defer podsMutex.RUnlock()
@@ -70,18 +85,28 @@ func FindPod(podName string) (*Pod, error) {
 	return selectedPod, nil
 }
 
-func RemovePodByManifestPath(manifestPath string) {
+// RemovePodByManifestPath removes the pod loaded from manifestPath and returns
+// its name, or an empty string if no loaded pod came from that manifest
+func RemovePodByManifestPath(manifestPath string) string {
 	relativePath := context.CurrentContext().GetSpiceAppRelativePath(manifestPath)
 	var podToDelete *Pod
+	podsMutex.RLock()
 	for _, pod := range pods {
 		if pod.ManifestPath() == manifestPath {
 			podToDelete = pod
 			break
 		}
 	}
+	podsMutex.RUnlock()
+
+	if podToDelete == nil {
+		return ""
+	}
 
 	log.Printf("Removing pod %s: %s\n", aurora.Bold(podToDelete.Name), aurora.Gray(12, relativePath))
 	RemovePod(podToDelete.Name)
+
+	return podToDelete.Name
 }
 
 func FindAllManifestPaths() []string {
@@ -103,16 +128,26 @@ func FindAllManifestPaths() []string {
 	return manifestPaths
 }
 
-func LoadPodFromManifest(manifestPath string) (*Pod, error) {
+// ReadPodManifest parses the manifest into a new pod without logging or checking
+// for an already-loaded pod of the same name
+func ReadPodManifest(manifestPath string) (*Pod, error) {
 	manifestHash, err := util.ComputeFileHash(manifestPath)
 	if err != nil {
-		log.Printf("Error: Failed to compute hash for manifest '%s: %s\n", manifestPath, err)
-		return nil, err
+		return nil, fmt.Errorf("failed to compute hash for manifest '%s': %w", manifestPath, err)
 	}
 
 	pod, err := loadPod(manifestPath, manifestHash)
 	if err != nil {
-		log.Printf("Error: Failed to load manifest '%s': %s\n", manifestPath, err)
+		return nil, fmt.Errorf("failed to load manifest '%s': %w", manifestPath, err)
+	}
+
+	return pod, nil
+}
+
+func LoadPodFromManifest(manifestPath string) (*Pod, error) {
+	pod, err := ReadPodManifest(manifestPath)
+	if err != nil {
+		log.Printf("Error: %s\n", err)
 		return nil, err
 	}
 
@@ -61,13 +61,14 @@ func (r *SpiceRuntime) SingleRun(manifestPath string) error {
 		return err
 	}
 
-	aiEngineReady := make(chan bool, 1)
-	err = aiengine.StartServer(aiEngineReady, true)
+	// The HTTP server starts first so the AI engine knows where to report episodes
+	err = r.startHttpServer()
 	if err != nil {
 		return err
 	}
 
-	err = spice_http.NewServer(runtime.config.HttpPort).Start()
+	aiEngineReady := make(chan bool, 1)
+	err = aiengine.StartServer(aiEngineReady, true)
 	if err != nil {
 		return err
 	}
@@ -108,13 +109,14 @@ func (r *SpiceRuntime) Run() error {
 		return err
 	}
 
-	aiEngineReady := make(chan bool)
-	err = aiengine.StartServer(aiEngineReady, false)
+	// The HTTP server starts first so the AI engine knows where to report episodes
+	err = r.startHttpServer()
 	if err != nil {
 		return err
 	}
 
-	err = spice_http.NewServer(runtime.config.HttpPort).Start()
+	aiEngineReady := make(chan bool)
+	err = aiengine.StartServer(aiEngineReady, false)
 	if err != nil {
 		return err
 	}
@@ -192,12 +194,39 @@ func (r *SpiceRuntime) printStartupBanner(runMode string) {
 		fmt.Print("- ")
 		fmt.Println(aurora.Yellow("Development mode"))
 	}
+	if r.config.HttpProfiling {
+		fmt.Print("- ")
+		fmt.Println(aurora.Yellow("Profiling endpoints enabled at /debug/pprof/ (loopback callers with http_profiling_token; a local proxy can expose them remotely)"))
+	}
 	fmt.Print("- ")
-	fmt.Println(aurora.Green(fmt.Sprintf("Listening on http://localhost:%d", runtime.config.HttpPort)))
+	fmt.Println(aurora.Green(fmt.Sprintf("Listening on %s", r.config.ServerBaseUrl())))
 	fmt.Println()
 	fmt.Println("Use Ctrl-C to stop")
 }
 
+func (r *SpiceRuntime) startHttpServer() error {
+	server := spice_http.NewServer(r.serverConfig())
+	err := server.Start()
+	if err != nil {
+		return err
+	}
+
+	aiengine.SetRuntimeUrl(server.CallbackUrl())
+
+	return nil
+}
+
+func (r *SpiceRuntime) serverConfig() spice_http.ServerConfig {
+	return spice_http.ServerConfig{
+		Port:           r.config.HttpPort,
+		TlsCertFile:    r.config.TlsCertFile,
+		TlsKeyFile:     r.config.TlsKeyFile,
+		Compression:    r.config.HttpCompression,
+		Profiling:      r.config.HttpProfiling,
+		ProfilingToken: r.config.HttpProfilingToken,
+	}
+}
+
 func (r *SpiceRuntime) scanForPods() error {
 	_, err := os.Stat(context.CurrentContext().AppDir())
 	if err != nil {
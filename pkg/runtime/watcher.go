@@ -128,7 +128,10 @@ func processPodManifestEvent(event fsnotify.Event) error {
 			return err
 		}
 	case fsnotify.Remove:
-		pods.RemovePodByManifestPath(manifestPath)
+		podName := pods.RemovePodByManifestPath(manifestPath)
+		if podName != "" {
+			environment.ForgetPod(podName)
+		}
 		return nil
 	}
 
@@ -136,6 +139,8 @@ func processPodManifestEvent(event fsnotify.Event) error {
 }
 
 func startNewPodTraining(pod *pods.Pod) error {
+	// Any earlier result belongs to the previous connectors
+	environment.ForgetPod(pod.Name)
 	pods.CreateOrUpdatePod(pod)
 
 	err := aiengine.InitializePod(pod)